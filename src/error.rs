@@ -0,0 +1,59 @@
+use thiserror::Error;
+
+/// Result type used throughout the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error is the set of errors that can be produced while configuring or
+/// driving a PeerConnection.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// ErrNoSuchScheme indicates an ICE server URL used a scheme other than
+    /// `stun`, `stuns`, `turn` or `turns`.
+    #[error("ICE server URL `{0}` uses an unknown scheme, expected one of stun/stuns/turn/turns")]
+    ErrNoSuchScheme(String),
+
+    /// ErrMissingScheme indicates an ICE server URL had no scheme at all.
+    #[error("ICE server URL `{0}` is missing a scheme")]
+    ErrMissingScheme(String),
+
+    /// ErrSTUNQuery indicates a `stun(s):` URL carried a query string, which
+    /// is forbidden by RFC 7064.
+    #[error("STUN ICE server URL `{0}` must not contain a query")]
+    ErrSTUNQuery(String),
+
+    /// ErrInvalidQuery indicates a `turn(s):` URL carried a query other than
+    /// the permitted `?transport=udp|tcp` (RFC 7065).
+    #[error("TURN ICE server URL `{0}` has an invalid query, only `?transport=udp|tcp` is allowed")]
+    ErrInvalidQuery(String),
+
+    /// ErrNoTURNCredentials indicates a `turn(s):` URL was supplied without
+    /// the mandatory `username`/`credential` pair.
+    #[error("TURN ICE server URL `{0}` is missing the required username/credential")]
+    ErrNoTURNCredentials(String),
+
+    /// ErrModifyingCertificates indicates an attempt to change the
+    /// certificates of a running PeerConnection via `set_configuration`.
+    #[error("certificates cannot be modified after the PeerConnection is constructed")]
+    ErrModifyingCertificates,
+
+    /// ErrModifyingBundlePolicy indicates an attempt to change the bundle
+    /// policy of a running PeerConnection via `set_configuration`.
+    #[error("bundle_policy cannot be modified after the PeerConnection is constructed")]
+    ErrModifyingBundlePolicy,
+
+    /// ErrModifyingRTCPMuxPolicy indicates an attempt to change the rtcp-mux
+    /// policy of a running PeerConnection via `set_configuration`.
+    #[error("rtcp_mux_policy cannot be modified after the PeerConnection is constructed")]
+    ErrModifyingRTCPMuxPolicy,
+
+    /// ErrModifyingPeerIdentity indicates an attempt to change the target peer
+    /// identity of a running PeerConnection via `set_configuration`.
+    #[error("peer_identity cannot be modified after the PeerConnection is constructed")]
+    ErrModifyingPeerIdentity,
+
+    /// ErrGatherFailed wraps an underlying I/O failure that occurred while an
+    /// ICE gatherer was binding its local host socket.
+    #[error("ICE gathering failed: {0}")]
+    ErrGatherFailed(String),
+}
@@ -0,0 +1,21 @@
+use std::net::SocketAddr;
+
+/// ICECandidateType represents the type of an ICE candidate as defined in
+/// https://tools.ietf.org/html/rfc8445#section-5.1.1.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ICECandidateType {
+    /// Host is a candidate obtained by binding to a local interface.
+    Host,
+    /// ServerReflexive is a candidate whose address was learned from a STUN
+    /// server.
+    ServerReflexive,
+    /// Relay is a candidate allocated on a TURN server.
+    Relay,
+}
+
+/// ICECandidate represents a single gathered local ICE candidate.
+#[derive(Debug, Clone)]
+pub struct ICECandidate {
+    pub typ: ICECandidateType,
+    pub address: SocketAddr,
+}
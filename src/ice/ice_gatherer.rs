@@ -0,0 +1,129 @@
+use std::net::UdpSocket;
+
+use crate::error::{Error, Result};
+use crate::ice::ice_candidate::{ICECandidate, ICECandidateType};
+use crate::ice::ice_server::ICEServer;
+use crate::policy::ice_transport_policy::ICETransportPolicy;
+
+/// ICEGathererState describes the lifecycle of an [`ICEGatherer`].
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum ICEGathererState {
+    /// The gatherer was created but has not started gathering yet.
+    #[default]
+    New,
+    /// The gatherer is actively gathering candidates.
+    Gathering,
+    /// The gatherer has been closed and its sockets released.
+    Closed,
+}
+
+/// ICEGatherer binds a local host socket and records placeholder STUN/TURN
+/// candidates for a single set of ICE servers; it does not contact the
+/// servers (see [`ICEGatherer::gather`]). It is the unit shared by the active ICE agent
+/// and by the prefetched candidate pool (see `ice_candidate_pool_size`).
+#[derive(Debug, Default)]
+pub struct ICEGatherer {
+    ice_servers: Vec<ICEServer>,
+    policy: ICETransportPolicy,
+    state: ICEGathererState,
+    /// The host socket is retained so that it (and therefore the host
+    /// candidate) stays alive until the gatherer is explicitly closed.
+    socket: Option<UdpSocket>,
+    candidates: Vec<ICECandidate>,
+}
+
+impl ICEGatherer {
+    /// Creates a gatherer for the given servers and transport policy. No
+    /// sockets are bound until [`ICEGatherer::gather`] is called.
+    pub(crate) fn new(ice_servers: Vec<ICEServer>, policy: ICETransportPolicy) -> Self {
+        ICEGatherer {
+            ice_servers,
+            policy,
+            ..Default::default()
+        }
+    }
+
+    /// gather binds a local host candidate and records the STUN/TURN
+    /// candidates that would be gathered against the configured servers. Host
+    /// and server-reflexive candidates are skipped when the transport policy is
+    /// `Relay`. The reflexive/relay entries are placeholders anchored at the
+    /// local base address (see the note in the body); no real STUN/TURN
+    /// round-trip or DNS resolution is performed.
+    pub(crate) fn gather(&mut self) -> Result<()> {
+        if self.state == ICEGathererState::Gathering {
+            return Ok(());
+        }
+        self.state = ICEGathererState::Gathering;
+        self.candidates.clear();
+
+        // "none" disables all candidate gathering; no sockets are bound and the
+        // configured ICE servers are ignored.
+        if self.policy == ICETransportPolicy::None {
+            return Ok(());
+        }
+
+        let socket =
+            UdpSocket::bind("0.0.0.0:0").map_err(|e| Error::ErrGatherFailed(e.to_string()))?;
+        let local_addr = socket
+            .local_addr()
+            .map_err(|e| Error::ErrGatherFailed(e.to_string()))?;
+
+        if self.policy != ICETransportPolicy::Relay {
+            self.candidates.push(ICECandidate {
+                typ: ICECandidateType::Host,
+                address: local_addr,
+            });
+        }
+        self.socket = Some(socket);
+
+        // NOTE: this is a stub. Real gathering would send a STUN Binding
+        // request (or a TURN Allocate) and learn the peer-visible address from
+        // the server's response. Here we only record that a reflexive/relay
+        // candidate *would* be gathered for each server, anchored at the local
+        // base transport address — never the STUN/TURN server's own address,
+        // which must not leak into SDP as if it were ours. The server URLs are
+        // not resolved, so construction never blocks on DNS.
+        for ice_server in &self.ice_servers {
+            for raw_url in &ice_server.urls {
+                let Some((scheme, _rest)) = raw_url.split_once(':') else {
+                    continue;
+                };
+                match scheme {
+                    "stun" | "stuns" if self.policy != ICETransportPolicy::Relay => {
+                        self.candidates.push(ICECandidate {
+                            typ: ICECandidateType::ServerReflexive,
+                            address: local_addr,
+                        });
+                    }
+                    "turn" | "turns" => {
+                        self.candidates.push(ICECandidate {
+                            typ: ICECandidateType::Relay,
+                            address: local_addr,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// candidates returns the candidates gathered so far.
+    pub(crate) fn candidates(&self) -> &[ICECandidate] {
+        &self.candidates
+    }
+
+    /// state returns the current lifecycle state of the gatherer.
+    pub(crate) fn state(&self) -> ICEGathererState {
+        self.state
+    }
+
+    /// close releases the host socket and marks the gatherer closed so that no
+    /// sockets are leaked.
+    pub(crate) fn close(&mut self) {
+        self.socket = None;
+        self.candidates.clear();
+        self.state = ICEGathererState::Closed;
+    }
+}
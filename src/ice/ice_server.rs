@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// ICECredentialType indicates the type of credentials used to connect to
+/// an ICE server.
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum ICECredentialType {
+    #[default]
+    #[serde(rename = "unspecified")]
+    Unspecified,
+
+    /// ICECredential::Password describes username and password based
+    /// credentials as described in
+    /// https://tools.ietf.org/html/rfc5389#section-10.2.
+    #[serde(rename = "password")]
+    Password,
+
+    /// ICECredential::Oauth describes token based credential as described
+    /// in https://tools.ietf.org/html/rfc7635.
+    #[serde(rename = "oauth")]
+    Oauth,
+}
+
+impl ICECredentialType {
+    /// returns true when no credential type was set. Used to omit the field
+    /// from serialized JSON, since `"unspecified"` is not a valid browser enum
+    /// value.
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == ICECredentialType::Unspecified
+    }
+}
+
+/// ICEServer describes a single STUN and TURN server that can be used by
+/// the ICEAgent to establish a connection with a peer.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ICEServer {
+    pub urls: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub credential: String,
+    #[serde(
+        default,
+        rename = "credentialType",
+        skip_serializing_if = "ICECredentialType::is_unspecified"
+    )]
+    pub credential_type: ICECredentialType,
+}
+
+impl ICEServer {
+    /// validate parses every URL of the server per RFC 7064/7065 and returns a
+    /// descriptive error on the first malformed entry:
+    ///
+    /// * the scheme must be one of `stun`, `stuns`, `turn` or `turns`;
+    /// * `stun(s):` URLs must not carry a query;
+    /// * `turn(s):` URLs may only carry `?transport=udp|tcp`;
+    /// * `turn(s):` URLs require both a username and a credential.
+    pub(crate) fn validate(&self) -> Result<()> {
+        for raw_url in &self.urls {
+            let Some((scheme, rest)) = raw_url.split_once(':') else {
+                return Err(Error::ErrMissingScheme(raw_url.clone()));
+            };
+
+            let (_host, query) = match rest.split_once('?') {
+                Some((host, query)) => (host, Some(query)),
+                None => (rest, None),
+            };
+
+            match scheme {
+                "stun" | "stuns" => {
+                    if query.is_some() {
+                        return Err(Error::ErrSTUNQuery(raw_url.clone()));
+                    }
+                }
+                "turn" | "turns" => {
+                    if let Some(query) = query {
+                        if !matches!(query, "transport=udp" | "transport=tcp") {
+                            return Err(Error::ErrInvalidQuery(raw_url.clone()));
+                        }
+                    }
+                    if self.username.is_empty() || self.credential.is_empty() {
+                        return Err(Error::ErrNoTURNCredentials(raw_url.clone()));
+                    }
+                }
+                _ => return Err(Error::ErrNoSuchScheme(raw_url.clone())),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod ice_candidate;
+pub mod ice_gatherer;
+pub mod ice_server;
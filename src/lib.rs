@@ -0,0 +1,4 @@
+pub mod error;
+pub mod ice;
+pub mod peer;
+pub mod policy;
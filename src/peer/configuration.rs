@@ -1,3 +1,4 @@
+use crate::error::Result;
 use crate::ice::ice_server::ICEServer;
 use crate::policy::bundle_policy::BundlePolicy;
 use crate::policy::ice_transport_policy::ICETransportPolicy;
@@ -5,13 +6,21 @@ use crate::policy::rtcp_mux_policy::RTCPMuxPolicy;
 use crate::policy::sdp_policy::SdpPolicy;
 
 use dtls::crypto::Certificate;
+use serde::{Deserialize, Serialize};
 
 /// A Configuration defines how peer-to-peer communication via PeerConnection
 /// is established or re-established.
 /// Configurations may be set up once and reused across multiple connections.
 /// Configurations are treated as readonly. As long as they are unmodified,
 /// they are safe for concurrent use.
-#[derive(Default, Clone)]
+///
+/// The serialized form matches the WebRTC `RTCConfiguration` dictionary: field
+/// names are camelCase (`iceServers`, `iceTransportPolicy`, ...) and enum values
+/// use the browser strings (`"relay"`, `"max-bundle"`, `"require"`, ...).
+/// `certificates` holds live DTLS `Certificate` objects and is skipped, so a
+/// deserialized Configuration always has an empty `certificates` list.
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Configuration {
     /// iceservers defines a slice describing servers available to be used by
     /// ICE, such as STUN and TURN servers.
@@ -19,19 +28,23 @@ pub struct Configuration {
 
     /// icetransport_policy indicates which candidates the ICEAgent is allowed
     /// to use.
+    #[serde(skip_serializing_if = "ICETransportPolicy::is_unspecified")]
     pub ice_transport_policy: ICETransportPolicy,
 
     /// bundle_policy indicates which media-bundling policy to use when gathering
     /// ICE candidates.
+    #[serde(skip_serializing_if = "BundlePolicy::is_unspecified")]
     pub bundle_policy: BundlePolicy,
 
     /// rtcp_mux_policy indicates which rtcp-mux policy to use when gathering ICE
     /// candidates.
+    #[serde(skip_serializing_if = "RTCPMuxPolicy::is_unspecified")]
     pub rtcp_mux_policy: RTCPMuxPolicy,
 
     /// peer_identity sets the target peer identity for the PeerConnection.
     /// The PeerConnection will not establish a connection to a remote peer
     /// unless it can be successfully authenticated with the provided name.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub peer_identity: String,
 
     /// Certificates describes a set of certificates that the PeerConnection
@@ -45,6 +58,7 @@ pub struct Configuration {
     /// used for a given connection; how certificates are selected is outside
     /// the scope of this specification. If this value is absent, then a default
     /// set of certificates is generated for each PeerConnection instance.
+    #[serde(skip)]
     pub certificates: Vec<Certificate>,
 
     /// icecandidate_pool_size describes the size of the prefetched ICE pool.
@@ -52,14 +66,33 @@ pub struct Configuration {
 
     /// sdp_policy controls the type of SDP offers accepted by and
     /// SDP answers generated by the PeerConnection.
+    #[serde(skip_serializing_if = "SdpPolicy::is_unspecified")]
     pub sdp_policy: SdpPolicy,
 }
 
 impl Configuration {
+    /// validate checks every configured ICE server URL against the strict
+    /// parsing rules of RFC 7064/7065 (see [`ICEServer::validate`]). It is
+    /// intended to be called from the PeerConnection constructor so that a
+    /// misconfigured `stun(s):`/`turn(s):` URL fails early rather than on the
+    /// first gathering attempt.
+    pub fn validate(&self) -> Result<()> {
+        for ice_server in &self.ice_servers {
+            ice_server.validate()?;
+        }
+        Ok(())
+    }
+
     /// get_iceservers side-steps the strict parsing mode of the ice package
     /// (as defined in https://tools.ietf.org/html/rfc7064) by copying and then
     /// stripping any erroneous queries from "stun(s):" URLs before parsing.
     pub(crate) fn get_ice_servers(&self) -> Vec<ICEServer> {
+        // With "iceTransports: none" the ICE agent gathers no candidates, so the
+        // server list is bypassed entirely.
+        if self.ice_transport_policy == ICETransportPolicy::None {
+            return Vec::new();
+        }
+
         let mut ice_servers = self.ice_servers.clone();
 
         for ice_server in &mut ice_servers {
@@ -113,11 +146,136 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_configuration_get_iceservers_none_policy() {
+        // "iceTransports: none" bypasses the server list entirely.
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["stun:stun.l.google.com:19302".to_owned()],
+                ..Default::default()
+            }],
+            ice_transport_policy: ICETransportPolicy::None,
+            ..Default::default()
+        };
+
+        assert!(cfg.get_ice_servers().is_empty());
+    }
+
+    #[test]
+    fn test_configuration_validate() {
+        use crate::error::Error;
+
+        let server = |url: &str| Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec![url.to_owned()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // wrong scheme
+        assert_eq!(
+            server("http://example.com").validate(),
+            Err(Error::ErrNoSuchScheme("http://example.com".to_owned()))
+        );
+
+        // no scheme at all
+        assert_eq!(
+            server("relative-url").validate(),
+            Err(Error::ErrMissingScheme("relative-url".to_owned()))
+        );
+
+        // TURN without credentials
+        assert_eq!(
+            server("turn:turn.example.org").validate(),
+            Err(Error::ErrNoTURNCredentials(
+                "turn:turn.example.org".to_owned()
+            ))
+        );
+        assert_eq!(
+            server("turns:turn.example.org").validate(),
+            Err(Error::ErrNoTURNCredentials(
+                "turns:turn.example.org".to_owned()
+            ))
+        );
+
+        // STUN must not carry a query
+        assert_eq!(
+            server("stun:stun.l.google.com:19302?transport=udp").validate(),
+            Err(Error::ErrSTUNQuery(
+                "stun:stun.l.google.com:19302?transport=udp".to_owned()
+            ))
+        );
+
+        // valid STUN
+        assert_eq!(server("stun:stun.l.google.com:19302").validate(), Ok(()));
+
+        // valid TURN with credentials and allowed transport query
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["turn:turn.example.org?transport=tcp".to_owned()],
+                username: "jch".to_owned(),
+                credential: "topsecret".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(cfg.validate(), Ok(()));
+
+        // TURN with a disallowed query
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["turn:turn.example.org?transport=sctp".to_owned()],
+                username: "jch".to_owned(),
+                credential: "topsecret".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            cfg.validate(),
+            Err(Error::ErrInvalidQuery(
+                "turn:turn.example.org?transport=sctp".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_configuration_json_omits_unspecified() {
+        // A default Configuration must not emit the internal "unspecified"
+        // sentinel, which is not a valid RTCConfiguration enum value and would
+        // be rejected by a browser.
+        let j = serde_json::to_string(&Configuration::default()).expect("marshal default");
+        assert!(
+            !j.contains("unspecified"),
+            "default config serialized the unspecified sentinel: {j}"
+        );
+
+        // A config that actually carries a (default-credential) STUN server
+        // must not emit the sentinel via `ICEServer::credential_type` either.
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["stun:stun.l.google.com:19302".to_owned()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let j = serde_json::to_string(&cfg).expect("marshal config with server");
+        assert!(
+            !j.contains("unspecified"),
+            "config with server serialized the unspecified sentinel: {j}"
+        );
+        assert!(
+            !j.contains("\"credential\":\"\""),
+            "empty credential should be omitted: {j}"
+        );
+    }
+
     #[test]
     fn test_configuration_json() {
-        /*TODO: let j = r#"
+        let j = r#"
             {
-                "iceServers": [{"URLs": ["turn:turn.example.org"],
+                "iceServers": [{"urls": ["turn:turn.example.org"],
                                 "username": "jch",
                                 "credential": "topsecret"
                               }],
@@ -126,28 +284,31 @@ mod test {
                 "rtcpMuxPolicy": "require"
             }"#;
 
-        conf := Configuration{
-            ICEServers: []ICEServer{
-                {
-                    URLs:       []string{"turn:turn.example.org"},
-                    Username:   "jch",
-                    Credential: "topsecret",
-                },
-            },
-            ICETransportPolicy: ICETransportPolicyRelay,
-            BundlePolicy:       BundlePolicyBalanced,
-            RTCPMuxPolicy:      RTCPMuxPolicyRequire,
-        }
+        let conf = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["turn:turn.example.org".to_owned()],
+                username: "jch".to_owned(),
+                credential: "topsecret".to_owned(),
+                ..Default::default()
+            }],
+            ice_transport_policy: ICETransportPolicy::Relay,
+            bundle_policy: BundlePolicy::Balanced,
+            rtcp_mux_policy: RTCPMuxPolicy::Require,
+            ..Default::default()
+        };
 
-        var conf2 Configuration
-        assert.NoError(t, json.Unmarshal([]byte(j), &conf2))
-        assert.Equal(t, conf, conf2)
+        let conf2: Configuration = serde_json::from_str(j).expect("unmarshal config");
+        assert_eq!(conf.ice_servers, conf2.ice_servers);
+        assert_eq!(conf.ice_transport_policy, conf2.ice_transport_policy);
+        assert_eq!(conf.bundle_policy, conf2.bundle_policy);
+        assert_eq!(conf.rtcp_mux_policy, conf2.rtcp_mux_policy);
 
-        j2, err := json.Marshal(conf2)
-        assert.NoError(t, err)
+        let j2 = serde_json::to_string(&conf2).expect("marshal config");
 
-        var conf3 Configuration
-        assert.NoError(t, json.Unmarshal(j2, &conf3))
-        assert.Equal(t, conf2, conf3)*/
+        let conf3: Configuration = serde_json::from_str(&j2).expect("re-unmarshal config");
+        assert_eq!(conf2.ice_servers, conf3.ice_servers);
+        assert_eq!(conf2.ice_transport_policy, conf3.ice_transport_policy);
+        assert_eq!(conf2.bundle_policy, conf3.bundle_policy);
+        assert_eq!(conf2.rtcp_mux_policy, conf3.rtcp_mux_policy);
     }
 }
\ No newline at end of file
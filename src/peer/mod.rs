@@ -0,0 +1,2 @@
+pub mod configuration;
+pub mod peer_connection;
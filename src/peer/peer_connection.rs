@@ -0,0 +1,334 @@
+use crate::error::{Error, Result};
+use crate::ice::ice_gatherer::ICEGatherer;
+use crate::peer::configuration::Configuration;
+use crate::policy::bundle_policy::BundlePolicy;
+use crate::policy::rtcp_mux_policy::RTCPMuxPolicy;
+
+/// A PeerConnection instance allows an application to establish peer-to-peer
+/// communications with another PeerConnection, or to another endpoint
+/// implementing the required protocols.
+pub struct PeerConnection {
+    configuration: Configuration,
+
+    /// The gatherer backing the active ICE agent. It is re-created whenever the
+    /// mutable ICE parameters change (an "ICE restart").
+    ice_gatherer: ICEGatherer,
+
+    /// Prefetched ICE gatherers (see `ice_candidate_pool_size`). Each has
+    /// already started gathering so that candidates are available before the
+    /// first offer is created; m-sections drain this pool as they are added.
+    candidate_pool: Vec<ICEGatherer>,
+}
+
+impl PeerConnection {
+    /// new constructs a PeerConnection from the supplied configuration. The
+    /// configuration is validated up-front so that a malformed ICE server URL
+    /// is reported here rather than on the first gathering attempt.
+    pub fn new(configuration: Configuration) -> Result<Self> {
+        configuration.validate()?;
+
+        let ice_gatherer = ICEGatherer::new(
+            configuration.get_ice_servers(),
+            configuration.ice_transport_policy,
+        );
+
+        let mut pc = PeerConnection {
+            configuration,
+            ice_gatherer,
+            candidate_pool: Vec::new(),
+        };
+
+        // Eagerly prefetch the ICE candidate pool so candidates are ready
+        // before the first createOffer/setLocalDescription.
+        pc.fill_candidate_pool()?;
+
+        Ok(pc)
+    }
+
+    /// get_configuration returns a clone of the PeerConnection's current
+    /// configuration, mirroring `RTCPeerConnection.getConfiguration()`.
+    pub fn get_configuration(&self) -> Configuration {
+        self.configuration.clone()
+    }
+
+    /// set_configuration applies the mutable subset of `configuration` to a
+    /// running PeerConnection, mirroring `RTCPeerConnection.setConfiguration()`.
+    ///
+    /// The immutable parameters (`certificates`, `bundle_policy`,
+    /// `rtcp_mux_policy` and `peer_identity`) may not be changed once the
+    /// PeerConnection exists and produce an error if altered. `certificates`
+    /// in particular are never applied here; supplying a non-empty set is
+    /// always rejected. Updating
+    /// `ice_servers` or `ice_transport_policy` triggers an ICE restart so that
+    /// candidates are re-gathered against the new settings (e.g. when rotating
+    /// short-lived TURN credentials).
+    pub fn set_configuration(&mut self, configuration: Configuration) -> Result<()> {
+        // https://www.w3.org/TR/webrtc/#set-the-configuration — reject any
+        // attempt to change an immutable field before touching live state.
+        if !configuration.peer_identity.is_empty()
+            && configuration.peer_identity != self.configuration.peer_identity
+        {
+            return Err(Error::ErrModifyingPeerIdentity);
+        }
+
+        // certificates are fixed at construction and are never applied by
+        // set_configuration, so the only accepted value is an empty list
+        // ("leave them unchanged"). Reject any supplied set outright rather
+        // than pretending to honour it.
+        if !configuration.certificates.is_empty() {
+            return Err(Error::ErrModifyingCertificates);
+        }
+
+        if configuration.bundle_policy != BundlePolicy::Unspecified
+            && configuration.bundle_policy != self.configuration.bundle_policy
+        {
+            return Err(Error::ErrModifyingBundlePolicy);
+        }
+
+        if configuration.rtcp_mux_policy != RTCPMuxPolicy::Unspecified
+            && configuration.rtcp_mux_policy != self.configuration.rtcp_mux_policy
+        {
+            return Err(Error::ErrModifyingRTCPMuxPolicy);
+        }
+
+        // Validate the new ICE servers before mutating any live state.
+        configuration.validate()?;
+
+        let ice_restart = self.configuration.ice_servers != configuration.ice_servers
+            || self.configuration.ice_transport_policy != configuration.ice_transport_policy;
+
+        // Apply the mutable subset.
+        self.configuration.ice_servers = configuration.ice_servers;
+        self.configuration.ice_transport_policy = configuration.ice_transport_policy;
+        self.configuration.ice_candidate_pool_size = configuration.ice_candidate_pool_size;
+
+        if ice_restart {
+            self.restart_ice()?;
+            // The pooled gatherers were bound against the old parameters; drop
+            // them so the pool refills against the new ICE servers/policy.
+            self.drain_candidate_pool();
+        }
+
+        // A raised pool size must prefetch more gatherers; a lowered one must
+        // release the surplus. fill_candidate_pool handles both directions.
+        self.fill_candidate_pool()?;
+
+        Ok(())
+    }
+
+    /// restart_ice tears down the active gatherer and starts a fresh one
+    /// against the current ICE servers and transport policy.
+    fn restart_ice(&mut self) -> Result<()> {
+        self.ice_gatherer.close();
+        self.ice_gatherer = ICEGatherer::new(
+            self.configuration.get_ice_servers(),
+            self.configuration.ice_transport_policy,
+        );
+        self.ice_gatherer.gather()
+    }
+
+    /// fill_candidate_pool grows or shrinks the prefetched ICE pool to match
+    /// `ice_candidate_pool_size`. New gatherers start gathering immediately;
+    /// surplus gatherers are closed so no sockets leak.
+    fn fill_candidate_pool(&mut self) -> Result<()> {
+        let target = self.configuration.ice_candidate_pool_size as usize;
+
+        while self.candidate_pool.len() > target {
+            if let Some(mut gatherer) = self.candidate_pool.pop() {
+                gatherer.close();
+            }
+        }
+
+        while self.candidate_pool.len() < target {
+            let mut gatherer = ICEGatherer::new(
+                self.configuration.get_ice_servers(),
+                self.configuration.ice_transport_policy,
+            );
+            gatherer.gather()?;
+            self.candidate_pool.push(gatherer);
+        }
+
+        Ok(())
+    }
+
+    /// drain_candidate_pool closes and removes every prefetched gatherer.
+    fn drain_candidate_pool(&mut self) {
+        for gatherer in &mut self.candidate_pool {
+            gatherer.close();
+        }
+        self.candidate_pool.clear();
+    }
+
+    /// take_pooled_gatherer removes a prefetched gatherer from the pool for use
+    /// by a newly added m-section, returning `None` once the pool is drained
+    /// (in which case the caller gathers on demand).
+    pub(crate) fn take_pooled_gatherer(&mut self) -> Option<ICEGatherer> {
+        self.candidate_pool.pop()
+    }
+
+    /// close tears down the active gatherer and the entire prefetched pool so
+    /// that no sockets are leaked after the PeerConnection is closed.
+    pub fn close(&mut self) {
+        self.ice_gatherer.close();
+        self.drain_candidate_pool();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ice::ice_server::ICEServer;
+    use crate::policy::ice_transport_policy::ICETransportPolicy;
+
+    fn stun_config() -> Configuration {
+        // IP literal so constructing a PeerConnection is hermetic (no DNS).
+        Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["stun:203.0.113.1:3478".to_owned()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_configuration_round_trips() {
+        let pc = PeerConnection::new(stun_config()).expect("construct");
+        let got = pc.get_configuration();
+        assert_eq!(got.ice_servers, stun_config().ice_servers);
+    }
+
+    #[test]
+    fn test_set_configuration_rejects_immutable_fields() {
+        let mut pc = PeerConnection::new(stun_config()).expect("construct");
+
+        let mut cfg = stun_config();
+        cfg.bundle_policy = BundlePolicy::MaxBundle;
+        assert_eq!(
+            pc.set_configuration(cfg),
+            Err(Error::ErrModifyingBundlePolicy)
+        );
+
+        let mut cfg = stun_config();
+        cfg.rtcp_mux_policy = RTCPMuxPolicy::Negotiate;
+        assert_eq!(
+            pc.set_configuration(cfg),
+            Err(Error::ErrModifyingRTCPMuxPolicy)
+        );
+
+        let mut cfg = stun_config();
+        cfg.peer_identity = "someone".to_owned();
+        assert_eq!(
+            pc.set_configuration(cfg),
+            Err(Error::ErrModifyingPeerIdentity)
+        );
+    }
+
+    #[test]
+    fn test_candidate_pool_prefetches_before_offer() {
+        use crate::ice::ice_candidate::ICECandidateType;
+
+        // Use an IP-literal STUN server so the reflexive path is exercised
+        // deterministically without relying on DNS.
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["stun:203.0.113.1:3478".to_owned()],
+                ..Default::default()
+            }],
+            ice_candidate_pool_size: 2,
+            ..Default::default()
+        };
+
+        let mut pc = PeerConnection::new(cfg).expect("construct");
+
+        // The pool is prefetched at construction, i.e. before any offer is
+        // created, and each gatherer already carries a host candidate plus the
+        // placeholder server-reflexive candidate recorded for the STUN server.
+        assert_eq!(pc.candidate_pool.len(), 2);
+        for gatherer in &pc.candidate_pool {
+            let types: Vec<_> = gatherer.candidates().iter().map(|c| c.typ).collect();
+            assert!(
+                types.contains(&ICECandidateType::Host),
+                "expected a prefetched host candidate before offer creation"
+            );
+            assert!(
+                types.contains(&ICECandidateType::ServerReflexive),
+                "expected a prefetched server-reflexive candidate before offer creation"
+            );
+            // The reflexive candidate must carry a local transport address,
+            // never the STUN server's own IP.
+            for candidate in gatherer.candidates() {
+                assert_ne!(
+                    candidate.address.ip().to_string(),
+                    "203.0.113.1",
+                    "candidate address leaked the STUN server's own address"
+                );
+            }
+        }
+
+        // m-sections drain the pool one gatherer at a time.
+        assert!(pc.take_pooled_gatherer().is_some());
+        assert_eq!(pc.candidate_pool.len(), 1);
+
+        // close tears the pool down entirely.
+        pc.close();
+        assert!(pc.candidate_pool.is_empty());
+    }
+
+    #[test]
+    fn test_none_policy_gathers_no_candidates() {
+        let mut cfg = stun_config();
+        cfg.ice_transport_policy = ICETransportPolicy::None;
+        cfg.ice_candidate_pool_size = 2;
+
+        let pc = PeerConnection::new(cfg).expect("construct");
+
+        // The pool is still sized, but every gatherer bound nothing and holds
+        // no candidates because gathering is disabled.
+        assert_eq!(pc.candidate_pool.len(), 2);
+        for gatherer in &pc.candidate_pool {
+            assert!(gatherer.candidates().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_set_configuration_resizes_candidate_pool() {
+        let pc_cfg = stun_config();
+        let mut pc = PeerConnection::new(pc_cfg).expect("construct");
+        assert!(pc.candidate_pool.is_empty());
+
+        let mut cfg = stun_config();
+        cfg.ice_candidate_pool_size = 3;
+        pc.set_configuration(cfg).expect("raise pool size");
+        assert_eq!(pc.candidate_pool.len(), 3);
+
+        let mut cfg = stun_config();
+        cfg.ice_candidate_pool_size = 1;
+        pc.set_configuration(cfg).expect("lower pool size");
+        assert_eq!(pc.candidate_pool.len(), 1);
+    }
+
+    #[test]
+    fn test_set_configuration_updates_mutable_fields() {
+        let mut pc = PeerConnection::new(stun_config()).expect("construct");
+
+        let cfg = Configuration {
+            ice_servers: vec![ICEServer {
+                urls: vec!["turn:198.51.100.1:3478".to_owned()],
+                username: "jch".to_owned(),
+                credential: "topsecret".to_owned(),
+                ..Default::default()
+            }],
+            ice_transport_policy: ICETransportPolicy::Relay,
+            ice_candidate_pool_size: 2,
+            ..Default::default()
+        };
+
+        pc.set_configuration(cfg).expect("apply mutable subset");
+
+        let got = pc.get_configuration();
+        assert_eq!(got.ice_transport_policy, ICETransportPolicy::Relay);
+        assert_eq!(got.ice_candidate_pool_size, 2);
+        assert_eq!(got.ice_servers[0].urls[0], "turn:198.51.100.1:3478");
+    }
+}
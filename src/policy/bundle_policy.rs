@@ -0,0 +1,70 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// BundlePolicy affects which media tracks are negotiated if the remote
+/// endpoint is not bundle-aware, and what ICE candidates are gathered. If the
+/// remote endpoint is bundle-aware, all media tracks and data channels are
+/// bundled onto the same transport.
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum BundlePolicy {
+    #[default]
+    #[serde(rename = "unspecified")]
+    Unspecified,
+
+    /// BundlePolicyBalanced indicates to gather ICE candidates for each
+    /// media type in use (audio, video, and data). If the remote endpoint is
+    /// not bundle-aware, negotiate only one audio and video track on separate
+    /// transports.
+    #[serde(rename = "balanced")]
+    Balanced,
+
+    /// BundlePolicyMaxCompat indicates to gather ICE candidates for each
+    /// track. If the remote endpoint is not bundle-aware, negotiate all media
+    /// tracks on separate transports.
+    #[serde(rename = "max-compat")]
+    MaxCompat,
+
+    /// BundlePolicyMaxBundle indicates to gather ICE candidates for only
+    /// one track. If the remote endpoint is not bundle-aware, negotiate only
+    /// one media track.
+    #[serde(rename = "max-bundle")]
+    MaxBundle,
+}
+
+impl BundlePolicy {
+    /// returns true when no policy was set. Used to omit the field from
+    /// serialized `RTCConfiguration` JSON, since `"unspecified"` is not a valid
+    /// browser enum value.
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == BundlePolicy::Unspecified
+    }
+}
+
+const BUNDLE_POLICY_BALANCED_STR: &str = "balanced";
+const BUNDLE_POLICY_MAX_COMPAT_STR: &str = "max-compat";
+const BUNDLE_POLICY_MAX_BUNDLE_STR: &str = "max-bundle";
+
+impl From<&str> for BundlePolicy {
+    /// takes a string and converts it to BundlePolicy
+    fn from(raw: &str) -> Self {
+        match raw {
+            BUNDLE_POLICY_BALANCED_STR => BundlePolicy::Balanced,
+            BUNDLE_POLICY_MAX_COMPAT_STR => BundlePolicy::MaxCompat,
+            BUNDLE_POLICY_MAX_BUNDLE_STR => BundlePolicy::MaxBundle,
+            _ => BundlePolicy::Unspecified,
+        }
+    }
+}
+
+impl fmt::Display for BundlePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match *self {
+            BundlePolicy::Balanced => BUNDLE_POLICY_BALANCED_STR,
+            BundlePolicy::MaxCompat => BUNDLE_POLICY_MAX_COMPAT_STR,
+            BundlePolicy::MaxBundle => BUNDLE_POLICY_MAX_BUNDLE_STR,
+            BundlePolicy::Unspecified => "unspecified",
+        };
+        write!(f, "{s}")
+    }
+}
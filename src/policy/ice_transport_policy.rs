@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// ICETransportPolicy defines the ICE candidate policy surface the
+/// permitted candidates. Only these candidates are used for connectivity
+/// checks.
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum ICETransportPolicy {
+    #[default]
+    #[serde(rename = "unspecified")]
+    Unspecified,
+
+    /// ICETransportPolicyNone indicates that no candidates are gathered at
+    /// all, disabling connectivity negotiation entirely. This is useful for
+    /// testing and offline/manual signaling flows.
+    #[serde(rename = "none")]
+    None,
+
+    /// ICETransportPolicyAll indicates any type of candidate is used.
+    #[serde(rename = "all")]
+    All,
+
+    /// ICETransportPolicyRelay indicates only media relay candidates such
+    /// as candidates passing through a TURN server are used.
+    #[serde(rename = "relay")]
+    Relay,
+}
+
+impl ICETransportPolicy {
+    /// returns true when no policy was set. Used to omit the field from
+    /// serialized `RTCConfiguration` JSON, since `"unspecified"` is not a valid
+    /// browser enum value.
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == ICETransportPolicy::Unspecified
+    }
+}
+
+const ICE_TRANSPORT_POLICY_RELAY_STR: &str = "relay";
+const ICE_TRANSPORT_POLICY_ALL_STR: &str = "all";
+const ICE_TRANSPORT_POLICY_NONE_STR: &str = "none";
+
+impl From<&str> for ICETransportPolicy {
+    /// takes a string and converts it to ICETransportPolicy
+    fn from(raw: &str) -> Self {
+        match raw {
+            ICE_TRANSPORT_POLICY_RELAY_STR => ICETransportPolicy::Relay,
+            ICE_TRANSPORT_POLICY_ALL_STR => ICETransportPolicy::All,
+            ICE_TRANSPORT_POLICY_NONE_STR => ICETransportPolicy::None,
+            _ => ICETransportPolicy::Unspecified,
+        }
+    }
+}
+
+impl fmt::Display for ICETransportPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match *self {
+            ICETransportPolicy::Relay => ICE_TRANSPORT_POLICY_RELAY_STR,
+            ICETransportPolicy::All => ICE_TRANSPORT_POLICY_ALL_STR,
+            ICETransportPolicy::None => ICE_TRANSPORT_POLICY_NONE_STR,
+            ICETransportPolicy::Unspecified => "unspecified",
+        };
+        write!(f, "{s}")
+    }
+}
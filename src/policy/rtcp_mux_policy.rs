@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// RTCPMuxPolicy affects what ICE candidates are gathered to support
+/// non-multiplexed RTCP.
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum RTCPMuxPolicy {
+    #[default]
+    #[serde(rename = "unspecified")]
+    Unspecified,
+
+    /// RTCPMuxPolicyNegotiate indicates to gather ICE candidates for both
+    /// RTP and RTCP candidates. If the remote-endpoint is capable of
+    /// multiplexing RTCP, multiplex RTCP on the RTP candidates. If it is not,
+    /// use both the RTP and RTCP candidates separately.
+    #[serde(rename = "negotiate")]
+    Negotiate,
+
+    /// RTCPMuxPolicyRequire indicates to gather ICE candidates only for
+    /// RTP and multiplex RTCP on the RTP candidates. If the remote endpoint is
+    /// not capable of rtcp-mux, session negotiation will fail.
+    #[serde(rename = "require")]
+    Require,
+}
+
+impl RTCPMuxPolicy {
+    /// returns true when no policy was set. Used to omit the field from
+    /// serialized `RTCConfiguration` JSON, since `"unspecified"` is not a valid
+    /// browser enum value.
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == RTCPMuxPolicy::Unspecified
+    }
+}
+
+const RTCP_MUX_POLICY_NEGOTIATE_STR: &str = "negotiate";
+const RTCP_MUX_POLICY_REQUIRE_STR: &str = "require";
+
+impl From<&str> for RTCPMuxPolicy {
+    /// takes a string and converts it to RTCPMuxPolicy
+    fn from(raw: &str) -> Self {
+        match raw {
+            RTCP_MUX_POLICY_NEGOTIATE_STR => RTCPMuxPolicy::Negotiate,
+            RTCP_MUX_POLICY_REQUIRE_STR => RTCPMuxPolicy::Require,
+            _ => RTCPMuxPolicy::Unspecified,
+        }
+    }
+}
+
+impl fmt::Display for RTCPMuxPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match *self {
+            RTCPMuxPolicy::Negotiate => RTCP_MUX_POLICY_NEGOTIATE_STR,
+            RTCPMuxPolicy::Require => RTCP_MUX_POLICY_REQUIRE_STR,
+            RTCPMuxPolicy::Unspecified => "unspecified",
+        };
+        write!(f, "{s}")
+    }
+}
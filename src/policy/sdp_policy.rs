@@ -0,0 +1,64 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// SdpPolicy controls the type of SDP offers accepted by and SDP answers
+/// generated by the PeerConnection.
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum SdpPolicy {
+    #[default]
+    #[serde(rename = "unspecified")]
+    Unspecified,
+
+    /// SdpPolicyUnifiedPlan uses unified-plan offers and answers (the
+    /// default and only fully spec-compliant semantics).
+    #[serde(rename = "unified-plan")]
+    UnifiedPlan,
+
+    /// SdpPolicyPlanB uses plan-b offers and answers. This is deprecated and
+    /// kept only for interoperability with legacy endpoints.
+    #[serde(rename = "plan-b")]
+    PlanB,
+
+    /// SdpPolicyUnifiedPlanWithFallback prefers unified-plan but falls back
+    /// to plan-b when the remote endpoint only understands plan-b.
+    #[serde(rename = "unified-plan-with-fallback")]
+    UnifiedPlanWithFallback,
+}
+
+impl SdpPolicy {
+    /// returns true when no policy was set. Used to omit the field from
+    /// serialized `RTCConfiguration` JSON, since `"unspecified"` is not a valid
+    /// browser enum value.
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == SdpPolicy::Unspecified
+    }
+}
+
+const SDP_POLICY_UNIFIED_PLAN_STR: &str = "unified-plan";
+const SDP_POLICY_PLAN_B_STR: &str = "plan-b";
+const SDP_POLICY_UNIFIED_PLAN_WITH_FALLBACK_STR: &str = "unified-plan-with-fallback";
+
+impl From<&str> for SdpPolicy {
+    /// takes a string and converts it to SdpPolicy
+    fn from(raw: &str) -> Self {
+        match raw {
+            SDP_POLICY_UNIFIED_PLAN_STR => SdpPolicy::UnifiedPlan,
+            SDP_POLICY_PLAN_B_STR => SdpPolicy::PlanB,
+            SDP_POLICY_UNIFIED_PLAN_WITH_FALLBACK_STR => SdpPolicy::UnifiedPlanWithFallback,
+            _ => SdpPolicy::Unspecified,
+        }
+    }
+}
+
+impl fmt::Display for SdpPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match *self {
+            SdpPolicy::UnifiedPlan => SDP_POLICY_UNIFIED_PLAN_STR,
+            SdpPolicy::PlanB => SDP_POLICY_PLAN_B_STR,
+            SdpPolicy::UnifiedPlanWithFallback => SDP_POLICY_UNIFIED_PLAN_WITH_FALLBACK_STR,
+            SdpPolicy::Unspecified => "unspecified",
+        };
+        write!(f, "{s}")
+    }
+}